@@ -0,0 +1,354 @@
+//! Text assembler for the mov-only instruction set.
+//!
+//! Produces the exact byte layout `Memory::load_opcode` expects: one
+//! big-endian 32-bit word per instruction, high 16 bits the source operand,
+//! low 16 bits the destination register. If bit `0x8000` of the source is
+//! set, the low 15 bits are a literal immediate (`0..=32767`); otherwise the
+//! source is a register index. The destination is always a register index.
+//!
+//! Source format, one instruction/directive per line:
+//!
+//! ```text
+//!     ; comments start with a semicolon
+//!     loop:             ; label, bound to the current word address
+//!         mov #10, cio
+//!         mov #loop, pc ; labels can also be used as immediates
+//!     .org 64           ; set the word address of the next instruction
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::registers::HALT_REGISTER;
+
+/// Symbolic register table mirroring `Registers::init_triggers`.
+const REGISTERS: &[(&str, u16)] = &[
+    ("add.a", 0),
+    ("add.b", 1),
+    ("add.out", 2),
+    ("sub.a", 3),
+    ("sub.b", 4),
+    ("sub.out", 5),
+    ("mul.a", 6),
+    ("mul.b", 7),
+    ("mul.out", 8),
+    ("div.a", 9),
+    ("div.b", 10),
+    ("div.out", 11),
+    ("div.rem", 12),
+    ("tlt.a", 13),
+    ("tlt.b", 14),
+    ("tlt.out", 15),
+    ("cio", 16),
+    ("io.out", 18),
+    ("io.in", 19),
+    ("atz.cond", 20),
+    ("atz.then", 21),
+    ("atz.else", 22),
+    ("atz.out", 23),
+    ("mem.data", 24),
+    ("mem.addr", 26),
+    ("pc", 27),
+    ("trap.vec", 28),
+    ("trap.cause", 29),
+    ("trap.pc", 30),
+    ("sp", 31),
+    ("call", 32),
+    ("ret", 33),
+    ("fadd.a", 36),
+    ("fadd.b", 37),
+    ("fadd.out", 38),
+    ("fsub.a", 39),
+    ("fsub.b", 40),
+    ("fsub.out", 41),
+    ("fmul.a", 42),
+    ("fmul.b", 43),
+    ("fmul.out", 44),
+    ("fdiv.a", 45),
+    ("fdiv.b", 46),
+    ("fdiv.out", 47),
+    ("fcmp.a", 48),
+    ("fcmp.b", 49),
+    ("fcmp.out", 50),
+    ("ftoi.in", 51),
+    ("ftoi.out", 52),
+    ("itof.in", 53),
+    ("itof.out", 54),
+    ("fp.round", 55),
+    ("halt", HALT_REGISTER as u16),
+];
+
+const IMMEDIATE_FLAG: u16 = 0x8000;
+const IMMEDIATE_MAX: i64 = 0x7FFF;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AssembleError {
+    /// Line `line` could not be parsed as `mov src, dst` or a directive.
+    Syntax { line: usize, text: String },
+    /// An operand referenced a register name that isn't in `REGISTERS`.
+    UnknownRegister { line: usize, name: String },
+    /// An operand referenced a label that was never defined.
+    UnknownLabel { line: usize, name: String },
+    /// An immediate is outside `0..=32767`.
+    ImmediateOutOfRange { line: usize, value: i64 },
+    /// `.org` must target a destination at or after the current address.
+    OrgGoesBackwards { line: usize, target: usize },
+}
+
+impl fmt::Display for AssembleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssembleError::Syntax { line, text } => {
+                write!(f, "line {line}: could not parse `{text}`")
+            }
+            AssembleError::UnknownRegister { line, name } => {
+                write!(f, "line {line}: unknown register `{name}`")
+            }
+            AssembleError::UnknownLabel { line, name } => {
+                write!(f, "line {line}: unknown label `{name}`")
+            }
+            AssembleError::ImmediateOutOfRange { line, value } => {
+                write!(f, "line {line}: immediate {value} out of range 0..=32767")
+            }
+            AssembleError::OrgGoesBackwards { line, target } => {
+                write!(f, "line {line}: .org {target} would move backwards")
+            }
+        }
+    }
+}
+
+impl core::error::Error for AssembleError {}
+
+fn register_index(name: &str) -> Option<u16> {
+    REGISTERS
+        .iter()
+        .find(|(reg_name, _)| *reg_name == name)
+        .map(|(_, index)| *index)
+}
+
+fn strip_comment(line: &str) -> &str {
+    match line.find(';') {
+        Some(pos) => &line[..pos],
+        None => line,
+    }
+}
+
+enum Line<'a> {
+    Label(&'a str),
+    Org(usize),
+    Mov { src: &'a str, dst: &'a str },
+}
+
+fn classify(line_no: usize, text: &str) -> Result<Option<Line<'_>>, AssembleError> {
+    let trimmed = strip_comment(text).trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+
+    if let Some(label) = trimmed.strip_suffix(':') {
+        return Ok(Some(Line::Label(label.trim())));
+    }
+
+    if let Some(rest) = trimmed.strip_prefix(".org") {
+        let rest = rest.trim();
+        let target = rest.parse::<usize>().map_err(|_| AssembleError::Syntax {
+            line: line_no,
+            text: text.to_string(),
+        })?;
+        return Ok(Some(Line::Org(target)));
+    }
+
+    if let Some(rest) = trimmed.strip_prefix("mov") {
+        let rest = rest.trim();
+        let (src, dst) = rest.split_once(',').ok_or_else(|| AssembleError::Syntax {
+            line: line_no,
+            text: text.to_string(),
+        })?;
+        return Ok(Some(Line::Mov {
+            src: src.trim(),
+            dst: dst.trim(),
+        }));
+    }
+
+    Err(AssembleError::Syntax {
+        line: line_no,
+        text: text.to_string(),
+    })
+}
+
+fn resolve_immediate(
+    line_no: usize,
+    operand: &str,
+    labels: &BTreeMap<String, usize>,
+) -> Result<i64, AssembleError> {
+    if let Ok(value) = operand.parse::<i64>() {
+        return Ok(value);
+    }
+    labels
+        .get(operand)
+        .map(|&addr| addr as i64)
+        .ok_or_else(|| AssembleError::UnknownLabel {
+            line: line_no,
+            name: operand.to_string(),
+        })
+}
+
+fn encode_src(line_no: usize, operand: &str, labels: &BTreeMap<String, usize>) -> Result<u16, AssembleError> {
+    if let Some(imm) = operand.strip_prefix('#') {
+        let value = resolve_immediate(line_no, imm, labels)?;
+        if !(0..=IMMEDIATE_MAX).contains(&value) {
+            return Err(AssembleError::ImmediateOutOfRange { line: line_no, value });
+        }
+        return Ok(IMMEDIATE_FLAG | value as u16);
+    }
+    register_index(operand).ok_or_else(|| AssembleError::UnknownRegister {
+        line: line_no,
+        name: operand.to_string(),
+    })
+}
+
+fn encode_dst(line_no: usize, operand: &str) -> Result<u16, AssembleError> {
+    register_index(operand).ok_or_else(|| AssembleError::UnknownRegister {
+        line: line_no,
+        name: operand.to_string(),
+    })
+}
+
+/// Assembles `source` into the packed big-endian word stream `Memory::store`
+/// expects, starting at word address 0 unless redirected by `.org`.
+pub fn assemble(source: &str) -> Result<Vec<u8>, AssembleError> {
+    // Pass 1: assign word addresses to labels.
+    let mut labels: BTreeMap<String, usize> = BTreeMap::new();
+    let mut addr = 0usize;
+    for (i, raw_line) in source.lines().enumerate() {
+        let line_no = i + 1;
+        match classify(line_no, raw_line)? {
+            None => {}
+            Some(Line::Label(name)) => {
+                labels.insert(name.to_string(), addr);
+            }
+            Some(Line::Org(target)) => {
+                if target < addr {
+                    return Err(AssembleError::OrgGoesBackwards { line: line_no, target });
+                }
+                addr = target;
+            }
+            Some(Line::Mov { .. }) => {
+                addr += 1;
+            }
+        }
+    }
+
+    // Pass 2: encode instructions now that every label is known.
+    let mut words: Vec<(usize, u32)> = Vec::new();
+    let mut addr = 0usize;
+    for (i, raw_line) in source.lines().enumerate() {
+        let line_no = i + 1;
+        match classify(line_no, raw_line)? {
+            None | Some(Line::Label(_)) => {}
+            Some(Line::Org(target)) => addr = target,
+            Some(Line::Mov { src, dst }) => {
+                let src = encode_src(line_no, src, &labels)?;
+                let dst = encode_dst(line_no, dst)?;
+                let word = ((src as u32) << 16) | dst as u32;
+                words.push((addr, word));
+                addr += 1;
+            }
+        }
+    }
+
+    let end = words.iter().map(|(addr, _)| addr + 1).max().unwrap_or(0);
+    let mut bytes = vec![0u8; end * 4];
+    for (addr, word) in words {
+        bytes[addr * 4..addr * 4 + 4].copy_from_slice(&word.to_be_bytes());
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word_at(bytes: &[u8], addr: usize) -> u32 {
+        u32::from_be_bytes(bytes[addr * 4..addr * 4 + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn encodes_register_to_register_mov() {
+        let bytes = assemble("mov add.out, cio\n").unwrap();
+        // src = register index 2 (add.out), dst = register index 16 (cio).
+        assert_eq!(word_at(&bytes, 0), (2u32 << 16) | 16);
+    }
+
+    #[test]
+    fn encodes_immediate_mov() {
+        let bytes = assemble("mov #65, cio\n").unwrap();
+        assert_eq!(word_at(&bytes, 0), ((IMMEDIATE_FLAG as u32 | 65) << 16) | 16);
+    }
+
+    #[test]
+    fn resolves_forward_and_backward_labels() {
+        let source = "\
+            mov #loop, pc\n\
+            loop:\n\
+            mov #1, cio\n\
+            mov #loop, pc\n";
+        let bytes = assemble(source).unwrap();
+        // `loop` is bound to word address 1.
+        assert_eq!(word_at(&bytes, 0) & 0xFFFF, 27); // dst = pc
+        assert_eq!((word_at(&bytes, 0) >> 16) & 0x7FFF, 1);
+        assert_eq!((word_at(&bytes, 2) >> 16) & 0x7FFF, 1);
+    }
+
+    #[test]
+    fn org_moves_the_write_cursor() {
+        let bytes = assemble(".org 2\nmov #1, cio\n").unwrap();
+        assert_eq!(bytes.len(), 3 * 4);
+        assert_eq!(word_at(&bytes, 2), ((IMMEDIATE_FLAG as u32 | 1) << 16) | 16);
+    }
+
+    #[test]
+    fn org_going_backwards_is_an_error() {
+        let err = assemble(".org 4\n.org 1\n").unwrap_err();
+        assert_eq!(err, AssembleError::OrgGoesBackwards { line: 2, target: 1 });
+    }
+
+    #[test]
+    fn unknown_register_is_an_error() {
+        let err = assemble("mov #1, not.a.register\n").unwrap_err();
+        assert_eq!(
+            err,
+            AssembleError::UnknownRegister {
+                line: 1,
+                name: "not.a.register".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_label_is_an_error() {
+        let err = assemble("mov #missing, pc\n").unwrap_err();
+        assert_eq!(
+            err,
+            AssembleError::UnknownLabel {
+                line: 1,
+                name: "missing".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn immediate_out_of_range_is_an_error() {
+        let err = assemble("mov #32768, cio\n").unwrap_err();
+        assert_eq!(err, AssembleError::ImmediateOutOfRange { line: 1, value: 32768 });
+    }
+
+    #[test]
+    fn halt_register_is_assemblable() {
+        let bytes = assemble("mov #1, halt\n").unwrap();
+        assert_eq!(word_at(&bytes, 0) & 0xFFFF, HALT_REGISTER as u32);
+    }
+}
@@ -0,0 +1,199 @@
+//! Disassembler for the mov-only instruction set, the inverse of
+//! [`crate::assembler`]. Gated behind the `disasm` cargo feature, as it
+//! isn't needed outside debugging/tooling builds.
+//!
+//! Each word is split using the same layout `Memory::load_opcode` uses:
+//! high 16 bits are the source, low 16 bits the destination. Register
+//! operands are rendered via the symbolic names from
+//! `Registers::init_triggers`, and a destination operand that hits a trigger
+//! register is annotated with the trigger it fires. Source operands are not
+//! annotated, even where reading them fires a trigger.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use crate::memory::Memory;
+use crate::registers::HALT_REGISTER;
+
+/// Symbolic register names keyed by index, matching `Registers::init_triggers`.
+const REGISTER_NAMES: &[(u16, &str)] = &[
+    (0, "add.a"),
+    (1, "add.b"),
+    (2, "add.out"),
+    (3, "sub.a"),
+    (4, "sub.b"),
+    (5, "sub.out"),
+    (6, "mul.a"),
+    (7, "mul.b"),
+    (8, "mul.out"),
+    (9, "div.a"),
+    (10, "div.b"),
+    (11, "div.out"),
+    (12, "div.rem"),
+    (13, "tlt.a"),
+    (14, "tlt.b"),
+    (15, "tlt.out"),
+    (16, "cio"),
+    (18, "io.out"),
+    (19, "io.in"),
+    (20, "atz.cond"),
+    (21, "atz.then"),
+    (22, "atz.else"),
+    (23, "atz.out"),
+    (24, "mem.data"),
+    (26, "mem.addr"),
+    (27, "pc"),
+    (28, "trap.vec"),
+    (29, "trap.cause"),
+    (30, "trap.pc"),
+    (31, "sp"),
+    (32, "call"),
+    (33, "ret"),
+    (36, "fadd.a"),
+    (37, "fadd.b"),
+    (38, "fadd.out"),
+    (39, "fsub.a"),
+    (40, "fsub.b"),
+    (41, "fsub.out"),
+    (42, "fmul.a"),
+    (43, "fmul.b"),
+    (44, "fmul.out"),
+    (45, "fdiv.a"),
+    (46, "fdiv.b"),
+    (47, "fdiv.out"),
+    (48, "fcmp.a"),
+    (49, "fcmp.b"),
+    (50, "fcmp.out"),
+    (51, "ftoi.in"),
+    (52, "ftoi.out"),
+    (53, "itof.in"),
+    (54, "itof.out"),
+    (55, "fp.round"),
+    (HALT_REGISTER as u16, "halt"),
+];
+
+/// Registers whose write side fires a trigger, annotated as `-- fires NAME`.
+const WRITE_TRIGGERS: &[(u16, &str)] = &[
+    (0, "add"),
+    (1, "add"),
+    (3, "sub"),
+    (4, "sub"),
+    (6, "mul"),
+    (7, "mul"),
+    (9, "div"),
+    (10, "div"),
+    (13, "tlt"),
+    (14, "tlt"),
+    (16, "cio"),
+    (18, "io"),
+    (20, "atz"),
+    (21, "atz"),
+    (22, "atz"),
+    (24, "mem"),
+    (26, "mem"),
+    (32, "call"),
+    (33, "ret"),
+    (36, "fadd"),
+    (37, "fadd"),
+    (39, "fsub"),
+    (40, "fsub"),
+    (42, "fmul"),
+    (43, "fmul"),
+    (45, "fdiv"),
+    (46, "fdiv"),
+    (48, "fcmp"),
+    (49, "fcmp"),
+    (51, "ftoi"),
+    (53, "itof"),
+    (HALT_REGISTER as u16, "halt"),
+];
+
+fn register_name(index: u16) -> String {
+    match REGISTER_NAMES.iter().find(|(i, _)| *i == index) {
+        Some((_, name)) => name.to_string(),
+        None => format!("r{index}"),
+    }
+}
+
+fn write_trigger(index: u16) -> Option<&'static str> {
+    WRITE_TRIGGERS
+        .iter()
+        .find(|(i, _)| *i == index)
+        .map(|(_, name)| *name)
+}
+
+fn disassemble_word(src: u16, dst: u16) -> String {
+    let src_text = if src & 0x8000 != 0 {
+        format!("#{}", src & 0x7FFF)
+    } else {
+        register_name(src)
+    };
+    let dst_text = register_name(dst);
+
+    match write_trigger(dst) {
+        Some(trigger) => format!("mov {src_text}, {dst_text} -- fires {trigger}"),
+        None => format!("mov {src_text}, {dst_text}"),
+    }
+}
+
+/// Disassembles `count` words starting at word address `base`. Never panics:
+/// out-of-range register indices are rendered as `rN`, and an unmapped page
+/// simply reads back as all-zero words (`Memory::load32` never bounds-checks
+/// since pages are allocated lazily).
+#[cfg(feature = "disasm")]
+pub fn disassemble(memory: &Memory, base: u64, count: u64) -> String {
+    let mut out = String::new();
+    for offset in 0..count {
+        let addr = base.wrapping_add(offset);
+        let (src, dst) = memory.load_opcode(addr);
+        out.push_str(&format!("{addr:>6}: {}\n", disassemble_word(src, dst)));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::io::BufferIo;
+
+    #[test]
+    fn renders_known_register_names() {
+        assert_eq!(
+            disassemble_word(16, 16),
+            "mov cio, cio -- fires cio"
+        );
+    }
+
+    #[test]
+    fn renders_out_of_range_register_as_rn() {
+        // 17 and 25 are gaps in REGISTER_NAMES (reserved but unused indices).
+        assert_eq!(disassemble_word(17, 25), "mov r17, r25");
+        assert_eq!(disassemble_word(9000, 9000), "mov r9000, r9000");
+    }
+
+    #[test]
+    fn renders_immediate_operands() {
+        assert_eq!(disassemble_word(0x8000, 16), "mov #0, cio -- fires cio");
+        assert_eq!(disassemble_word(0x8000 | 0x7FFF, 16), "mov #32767, cio -- fires cio");
+    }
+
+    #[test]
+    fn renders_halt_register() {
+        assert_eq!(
+            disassemble_word(0x8001, HALT_REGISTER as u16),
+            "mov #1, halt -- fires halt"
+        );
+    }
+
+    #[test]
+    fn disassemble_never_panics_on_unmapped_memory() {
+        let memory = Memory::new(Box::new(BufferIo::new(core::iter::empty())));
+        // Nothing was ever stored, so every word reads back as all-zero —
+        // this should format cleanly rather than panicking or erroring.
+        let out = disassemble(&memory, 1_000_000, 4);
+        assert_eq!(out.lines().count(), 4);
+        assert!(out.contains("mov add.a, add.a"));
+    }
+}
@@ -0,0 +1,49 @@
+//! Character I/O behind a small trait, so `Memory` isn't hardwired to a
+//! particular terminal backend.
+//!
+//! `cio_trig`/`io_trig` used to reach straight into `termion`'s
+//! `AsyncReader`/`RawTerminal`, which meant the VM could only run attached
+//! to a real terminal. They now go through `VmIo`, and `Memory` just holds a
+//! `Box<dyn VmIo>`. The termion-backed implementation lives in the `vm`
+//! binary crate (the one part of the workspace that isn't `no_std`);
+//! `BufferIo` below needs nothing beyond `alloc` and is what this crate's
+//! own tests, and any headless/embedded caller, should use instead.
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+pub trait VmIo {
+    /// Non-blocking read: `None` if no byte is currently available.
+    fn read_byte(&mut self) -> Option<u8>;
+    fn write_byte(&mut self, byte: u8);
+    /// Called when `cio` receives the clear-screen sentinel (256). Terminal
+    /// backends redraw; buffer-backed backends can ignore it.
+    fn clear_screen(&mut self) {}
+}
+
+/// Buffer-backed `VmIo` for tests and headless embedding: reads drain a
+/// preloaded input queue, writes accumulate into an output buffer.
+#[derive(Default)]
+pub struct BufferIo {
+    input: VecDeque<u8>,
+    pub output: Vec<u8>,
+}
+
+impl BufferIo {
+    pub fn new(input: impl IntoIterator<Item = u8>) -> Self {
+        BufferIo {
+            input: input.into_iter().collect(),
+            output: Vec::new(),
+        }
+    }
+}
+
+impl VmIo for BufferIo {
+    fn read_byte(&mut self) -> Option<u8> {
+        self.input.pop_front()
+    }
+
+    fn write_byte(&mut self, byte: u8) {
+        self.output.push(byte);
+    }
+}
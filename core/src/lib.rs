@@ -0,0 +1,24 @@
+//! Core of the mov-only transport-triggered VM: registers, paged memory, the
+//! single-step execution function, the assembler, and (optionally) the
+//! disassembler.
+//!
+//! `no_std` outside of tests, so this crate can run wherever `alloc` can be
+//! provided — the only part of the VM that needs `std` is the `vm` binary
+//! crate, whose stdout and `termion` terminal backends talk to this one only
+//! through [`io::VmIo`].
+
+#![cfg_attr(not(test), no_std)]
+
+extern crate alloc;
+
+pub mod assembler;
+#[cfg(feature = "disasm")]
+pub mod disasm;
+pub mod io;
+pub mod memory;
+pub mod registers;
+pub mod trap;
+
+pub use memory::Memory;
+pub use registers::{step, Registers};
+pub use trap::Trap;
@@ -0,0 +1,120 @@
+//! Paged, sparse word-addressed memory plus the character I/O seam.
+//!
+//! Pages are allocated lazily on first write and addresses are full 64-bit
+//! word indices, so a program can store data arbitrarily far out without
+//! pre-allocating a fixed-size buffer; reads of an unmapped page simply come
+//! back as zero.
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+
+use crate::io::VmIo;
+
+/// Words per page.
+pub const PAGE_WORDS: usize = 4096;
+
+pub struct Memory {
+    pages: BTreeMap<u64, Box<[u32; PAGE_WORDS]>>,
+    pub io: Box<dyn VmIo>,
+}
+
+impl Memory {
+    pub fn new(io: Box<dyn VmIo>) -> Self {
+        Memory {
+            pages: BTreeMap::new(),
+            io,
+        }
+    }
+
+    fn page_and_offset(address: u64) -> (u64, usize) {
+        (
+            address / PAGE_WORDS as u64,
+            (address % PAGE_WORDS as u64) as usize,
+        )
+    }
+
+    pub fn load32(&self, address: u64) -> u32 {
+        let (page, offset) = Self::page_and_offset(address);
+        self.pages.get(&page).map_or(0, |words| words[offset])
+    }
+
+    pub fn store32(&mut self, address: u64, value: u32) {
+        let (page, offset) = Self::page_and_offset(address);
+        let words = self
+            .pages
+            .entry(page)
+            .or_insert_with(|| Box::new([0; PAGE_WORDS]));
+        words[offset] = value;
+    }
+
+    pub fn load_opcode(&self, address: u64) -> (u16, u16) {
+        let word = self.load32(address);
+        ((word >> 16) as u16, (word & 0xFFFF) as u16)
+    }
+
+    pub fn load64(&self, address: u64) -> u64 {
+        self.load32(address * 2) as u64 * 4294967296 + self.load32(address * 2 + 1) as u64
+    }
+
+    pub fn store64(&mut self, address: u64, value: u64) {
+        self.store32(address * 2, (value / 4294967296) as u32);
+        self.store32(address * 2 + 1, (value % 4294967296) as u32);
+    }
+
+    pub fn store(&mut self, data: &[u8], base: u64) {
+        for (i, word) in data.chunks_exact(4).enumerate() {
+            let word = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+            self.store32(base + i as u64, word);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::io::BufferIo;
+
+    fn test_memory() -> Memory {
+        Memory::new(Box::new(BufferIo::default()))
+    }
+
+    #[test]
+    fn unmapped_address_reads_as_zero() {
+        let mem = test_memory();
+        assert_eq!(mem.load32(123_456_789), 0);
+    }
+
+    #[test]
+    fn store_allocates_only_the_touched_page() {
+        let mut mem = test_memory();
+        mem.store32(5, 42);
+        assert_eq!(mem.pages.len(), 1);
+        assert_eq!(mem.load32(5), 42);
+        // Reading a different, never-written page doesn't allocate it.
+        assert_eq!(mem.load32(PAGE_WORDS as u64 * 10), 0);
+        assert_eq!(mem.pages.len(), 1);
+    }
+
+    #[test]
+    fn load64_store64_round_trip_across_a_page_boundary() {
+        let mut mem = test_memory();
+        // A load64/store64 pair always touches two consecutive 32-bit words
+        // (2n, 2n+1), which land on the same page since PAGE_WORDS is even —
+        // so no single 64-bit access can straddle a page boundary. Exercise
+        // the next best thing instead: one 64-bit word living at the very
+        // end of a page and the next at the very start of the following
+        // page, and check `page_and_offset`'s boundary math doesn't corrupt
+        // either.
+        let last_of_page0 = (PAGE_WORDS / 2 - 1) as u64;
+        let first_of_page1 = (PAGE_WORDS / 2) as u64;
+
+        mem.store64(last_of_page0, 0x1122334455667788);
+        mem.store64(first_of_page1, 0x99AABBCCDDEEFF00);
+
+        assert_eq!(mem.load64(last_of_page0), 0x1122334455667788);
+        assert_eq!(mem.load64(first_of_page1), 0x99AABBCCDDEEFF00);
+        assert_eq!(mem.pages.len(), 2);
+    }
+}
@@ -0,0 +1,565 @@
+//! The register file, its transport triggers, and the single-step execution
+//! function that drives them.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::memory::Memory;
+use crate::trap::Trap;
+
+/// Register holding the program counter: the word address of the next
+/// instruction to execute.
+pub const PC_REGISTER: usize = 27;
+
+/// Register holding the handler word address for `trap.vec`. Zero means
+/// "no handler installed" — a trap then halts the VM.
+pub const TRAP_VEC_REGISTER: usize = 28;
+/// Register the trap handler can read to learn which `Trap` fired.
+pub const TRAP_CAUSE_REGISTER: usize = 29;
+/// Register holding the faulting `pc`, so a handler can resume after it.
+pub const TRAP_PC_REGISTER: usize = 30;
+
+/// Stack-pointer register for the call/return subsystem: a 64-bit-word
+/// address (same addressing unit as `mem.addr`) pointing at the last
+/// pushed frame, full-descending (push decrements then stores). Defaults to
+/// 0, so a program that calls `call` before pointing `sp` somewhere sane
+/// immediately traps `MemoryOutOfBounds` on the decrement.
+pub const SP_REGISTER: usize = 31;
+/// Writing a target word address here pushes `pc` (already advanced past
+/// this instruction) and jumps to the target.
+pub const CALL_REGISTER: usize = 32;
+/// Writing any value here pops the last return address and jumps to it.
+pub const RET_REGISTER: usize = 33;
+
+/// Floating-point ALU registers. The operands and outputs of fadd, fsub,
+/// fmul and fdiv, and fcmp's operands, each hold an `i64` that's really the
+/// bit pattern of an `f64` (`f64::to_bits`/`from_bits`), so NaN and infinity
+/// round-trip through these registers losslessly. `FCMP_OUT` holds a plain
+/// integer 0 or 1.
+pub const FADD_A: usize = 36;
+pub const FADD_B: usize = 37;
+pub const FADD_OUT: usize = 38;
+pub const FSUB_A: usize = 39;
+pub const FSUB_B: usize = 40;
+pub const FSUB_OUT: usize = 41;
+pub const FMUL_A: usize = 42;
+pub const FMUL_B: usize = 43;
+pub const FMUL_OUT: usize = 44;
+pub const FDIV_A: usize = 45;
+pub const FDIV_B: usize = 46;
+pub const FDIV_OUT: usize = 47;
+pub const FCMP_A: usize = 48;
+pub const FCMP_B: usize = 49;
+pub const FCMP_OUT: usize = 50;
+/// Writing here converts the bit-reinterpreted `f64` to `i64` into
+/// `FTOI_OUT`, rounding per `FP_ROUND_MODE`.
+pub const FTOI_IN: usize = 51;
+pub const FTOI_OUT: usize = 52;
+/// Writing here converts the `i64` to an `f64` (bit pattern) into
+/// `ITOF_OUT`. `FP_ROUND_MODE` has no effect here: the underlying `as f64`
+/// cast always rounds to nearest, and there's no other meaningful rounding
+/// for integer-to-float widening without manual bit manipulation.
+pub const ITOF_IN: usize = 53;
+pub const ITOF_OUT: usize = 54;
+/// 0 = nearest, 1 = toward zero, 2 = up, 3 = down. Consulted by `FTOI_IN`.
+pub const FP_ROUND_MODE: usize = 55;
+
+/// Writing a nonzero value here stops the execution loop. Addresses are
+/// 64-bit and pages are allocated lazily, so unlike the fixed-size-memory
+/// sentinel this used to be, halting isn't tied to any particular address —
+/// code can live anywhere in the word space and still signal that it's done.
+pub const HALT_REGISTER: usize = 56;
+
+pub const REGISTER_COUNT: usize = 57;
+
+type TriggerFn = fn(usize, &mut [i64; REGISTER_COUNT], &mut Memory) -> Result<(), Trap>;
+
+pub struct Registers {
+    pub buffer: [i64; REGISTER_COUNT],
+    triggers: BTreeMap<usize, (Vec<TriggerFn>, Vec<TriggerFn>)>,
+}
+
+impl Registers {
+    pub fn new() -> Self {
+        let mut regs = Registers {
+            buffer: [0; REGISTER_COUNT],
+            triggers: BTreeMap::new(),
+        };
+        regs.init_triggers();
+        regs
+    }
+
+    fn get_triggers_pair(&mut self, index: usize) -> &mut (Vec<TriggerFn>, Vec<TriggerFn>) {
+        self.triggers
+            .entry(index)
+            .or_insert((Vec::new(), Vec::new()))
+    }
+
+    fn init_triggers(&mut self) {
+        fn add_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], _memory: &mut Memory) -> Result<(), Trap> {
+            buffer[2] = buffer[0] + buffer[1];
+            Ok(())
+        }
+        self.get_triggers_pair(0).1.push(add_trig);
+        self.get_triggers_pair(1).1.push(add_trig);
+        self.get_triggers_pair(2).0.push(add_trig);
+
+        fn sub_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], _memory: &mut Memory) -> Result<(), Trap> {
+            buffer[5] = buffer[3] - buffer[4];
+            Ok(())
+        }
+        self.get_triggers_pair(3).1.push(sub_trig);
+        self.get_triggers_pair(4).1.push(sub_trig);
+        self.get_triggers_pair(5).0.push(sub_trig);
+
+        fn mul_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], _memory: &mut Memory) -> Result<(), Trap> {
+            buffer[8] = buffer[6] * buffer[7];
+            Ok(())
+        }
+        self.get_triggers_pair(6).1.push(mul_trig);
+        self.get_triggers_pair(7).1.push(mul_trig);
+        self.get_triggers_pair(8).0.push(mul_trig);
+
+        fn div_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], _memory: &mut Memory) -> Result<(), Trap> {
+            let div0 = buffer[9];
+            let div1 = buffer[10];
+            if div1 == 0 {
+                return Err(Trap::DivideByZero);
+            }
+            buffer[11] = div0 / div1;
+            buffer[12] = div0 % div1;
+            Ok(())
+        }
+        self.get_triggers_pair(9).1.push(div_trig);
+        self.get_triggers_pair(10).1.push(div_trig);
+        self.get_triggers_pair(11).0.push(div_trig);
+        self.get_triggers_pair(12).0.push(div_trig);
+
+        fn tlt_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], _memory: &mut Memory) -> Result<(), Trap> {
+            buffer[15] = if buffer[13] < buffer[14] { 1 } else { 0 };
+            Ok(())
+        }
+        self.get_triggers_pair(13).1.push(tlt_trig);
+        self.get_triggers_pair(14).1.push(tlt_trig);
+        self.get_triggers_pair(15).0.push(tlt_trig);
+
+        fn cio_trig(trig: usize, buffer: &mut [i64; REGISTER_COUNT], memory: &mut Memory) -> Result<(), Trap> {
+            if trig == 1 {
+                if buffer[16] == 256 {
+                    memory.io.clear_screen();
+                    return Ok(());
+                }
+                let byte = u8::try_from(buffer[16]).map_err(|_| Trap::InvalidChar)?;
+                memory.io.write_byte(byte);
+            } else {
+                buffer[16] = match memory.io.read_byte() {
+                    Some(v) => v.into(),
+                    None => -1,
+                };
+            }
+            Ok(())
+        }
+        self.get_triggers_pair(16).0.push(cio_trig);
+        self.get_triggers_pair(16).1.push(cio_trig);
+
+        fn io_trig(trig: usize, buffer: &mut [i64; REGISTER_COUNT], memory: &mut Memory) -> Result<(), Trap> {
+            if trig == 1 {
+                let byte = u8::try_from(buffer[18]).map_err(|_| Trap::InvalidChar)?;
+                memory.io.write_byte(byte);
+            } else {
+                buffer[19] = 10;
+            }
+            Ok(())
+        }
+        self.get_triggers_pair(18).1.push(io_trig);
+        self.get_triggers_pair(19).0.push(io_trig);
+
+        fn atz_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], _memory: &mut Memory) -> Result<(), Trap> {
+            buffer[23] = if buffer[20] == 0 {
+                buffer[21]
+            } else {
+                buffer[22]
+            };
+            Ok(())
+        }
+        self.get_triggers_pair(20).1.push(atz_trig);
+        self.get_triggers_pair(21).1.push(atz_trig);
+        self.get_triggers_pair(22).1.push(atz_trig);
+        self.get_triggers_pair(23).0.push(atz_trig);
+
+        fn mem_trig(trig: usize, buffer: &mut [i64; REGISTER_COUNT], memory: &mut Memory) -> Result<(), Trap> {
+            let address: u64 = buffer[26].try_into().map_err(|_| Trap::MemoryOutOfBounds)?;
+            if trig == 1 {
+                memory.store64(address, buffer[24] as u64);
+            } else {
+                buffer[24] = memory.load64(address) as i64;
+            }
+            Ok(())
+        }
+        self.get_triggers_pair(24).0.push(mem_trig);
+        self.get_triggers_pair(24).1.push(mem_trig);
+        self.get_triggers_pair(26).1.push(mem_trig);
+
+        fn call_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], memory: &mut Memory) -> Result<(), Trap> {
+            let sp: u64 = buffer[SP_REGISTER]
+                .try_into()
+                .map_err(|_| Trap::MemoryOutOfBounds)?;
+            let sp = sp.checked_sub(1).ok_or(Trap::MemoryOutOfBounds)?;
+            memory.store64(sp, buffer[PC_REGISTER] as u64);
+            buffer[SP_REGISTER] = sp as i64;
+            buffer[PC_REGISTER] = buffer[CALL_REGISTER];
+            Ok(())
+        }
+        self.get_triggers_pair(CALL_REGISTER).1.push(call_trig);
+
+        fn ret_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], memory: &mut Memory) -> Result<(), Trap> {
+            let sp: u64 = buffer[SP_REGISTER]
+                .try_into()
+                .map_err(|_| Trap::MemoryOutOfBounds)?;
+            buffer[PC_REGISTER] = memory.load64(sp) as i64;
+            buffer[SP_REGISTER] = sp.checked_add(1).ok_or(Trap::MemoryOutOfBounds)? as i64;
+            Ok(())
+        }
+        self.get_triggers_pair(RET_REGISTER).1.push(ret_trig);
+
+        fn fadd_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], _memory: &mut Memory) -> Result<(), Trap> {
+            let a = f64::from_bits(buffer[FADD_A] as u64);
+            let b = f64::from_bits(buffer[FADD_B] as u64);
+            buffer[FADD_OUT] = (a + b).to_bits() as i64;
+            Ok(())
+        }
+        self.get_triggers_pair(FADD_A).1.push(fadd_trig);
+        self.get_triggers_pair(FADD_B).1.push(fadd_trig);
+
+        fn fsub_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], _memory: &mut Memory) -> Result<(), Trap> {
+            let a = f64::from_bits(buffer[FSUB_A] as u64);
+            let b = f64::from_bits(buffer[FSUB_B] as u64);
+            buffer[FSUB_OUT] = (a - b).to_bits() as i64;
+            Ok(())
+        }
+        self.get_triggers_pair(FSUB_A).1.push(fsub_trig);
+        self.get_triggers_pair(FSUB_B).1.push(fsub_trig);
+
+        fn fmul_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], _memory: &mut Memory) -> Result<(), Trap> {
+            let a = f64::from_bits(buffer[FMUL_A] as u64);
+            let b = f64::from_bits(buffer[FMUL_B] as u64);
+            buffer[FMUL_OUT] = (a * b).to_bits() as i64;
+            Ok(())
+        }
+        self.get_triggers_pair(FMUL_A).1.push(fmul_trig);
+        self.get_triggers_pair(FMUL_B).1.push(fmul_trig);
+
+        // IEEE-754 division by zero produces infinity/NaN rather than
+        // trapping, unlike the integer `div` trigger.
+        fn fdiv_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], _memory: &mut Memory) -> Result<(), Trap> {
+            let a = f64::from_bits(buffer[FDIV_A] as u64);
+            let b = f64::from_bits(buffer[FDIV_B] as u64);
+            buffer[FDIV_OUT] = (a / b).to_bits() as i64;
+            Ok(())
+        }
+        self.get_triggers_pair(FDIV_A).1.push(fdiv_trig);
+        self.get_triggers_pair(FDIV_B).1.push(fdiv_trig);
+
+        fn fcmp_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], _memory: &mut Memory) -> Result<(), Trap> {
+            let a = f64::from_bits(buffer[FCMP_A] as u64);
+            let b = f64::from_bits(buffer[FCMP_B] as u64);
+            buffer[FCMP_OUT] = if a < b { 1 } else { 0 };
+            Ok(())
+        }
+        self.get_triggers_pair(FCMP_A).1.push(fcmp_trig);
+        self.get_triggers_pair(FCMP_B).1.push(fcmp_trig);
+
+        fn ftoi_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], _memory: &mut Memory) -> Result<(), Trap> {
+            let value = f64::from_bits(buffer[FTOI_IN] as u64);
+            let rounded = match buffer[FP_ROUND_MODE] {
+                1 => libm::trunc(value),
+                2 => libm::ceil(value),
+                3 => libm::floor(value),
+                _ => libm::round(value),
+            };
+            // `as i64` on a float saturates (NaN -> 0, +-inf -> i64::MAX/MIN)
+            // rather than panicking or producing an unspecified bit pattern.
+            buffer[FTOI_OUT] = rounded as i64;
+            Ok(())
+        }
+        self.get_triggers_pair(FTOI_IN).1.push(ftoi_trig);
+
+        fn itof_trig(_trig: usize, buffer: &mut [i64; REGISTER_COUNT], _memory: &mut Memory) -> Result<(), Trap> {
+            buffer[ITOF_OUT] = (buffer[ITOF_IN] as f64).to_bits() as i64;
+            Ok(())
+        }
+        self.get_triggers_pair(ITOF_IN).1.push(itof_trig);
+    }
+
+    pub fn set(&mut self, index: usize, value: i64, memory: &mut Memory) -> Result<(), Trap> {
+        if index >= self.buffer.len() {
+            return Err(Trap::InvalidRegister);
+        }
+        self.buffer[index] = value;
+
+        if let Some(trigs) = self.triggers.get(&index) {
+            let buf = &mut (self.buffer);
+            for callback in trigs.1.iter() {
+                callback(1, buf, memory)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get(&mut self, index: usize, memory: &mut Memory) -> Result<i64, Trap> {
+        if index >= self.buffer.len() {
+            return Err(Trap::InvalidRegister);
+        }
+        if let Some(trigs) = self.triggers.get(&index) {
+            let buf = &mut (self.buffer);
+            for callback in trigs.0.iter() {
+                callback(0, buf, memory)?;
+            }
+        }
+        Ok(self.buffer[index])
+    }
+}
+
+impl Default for Registers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Executes one `mov src, dst` instruction at `regs.buffer[PC_REGISTER]`.
+///
+/// Returns `Ok(true)` to keep running, `Ok(false)` once `HALT_REGISTER` has
+/// been set nonzero, and `Err` for a trap that reached `main` because no
+/// handler was installed in `TRAP_VEC_REGISTER`.
+pub fn step(regs: &mut Registers, mem: &mut Memory) -> Result<bool, Trap> {
+    let addr = regs.buffer[PC_REGISTER] as u64;
+    let (src, dst) = mem.load_opcode(addr);
+
+    let outcome = (|| -> Result<(), Trap> {
+        let val = if src & 0x8000 != 0 {
+            (src & 0x7FFF) as i64
+        } else {
+            regs.get(src.into(), mem)?
+        };
+        regs.buffer[PC_REGISTER] = (addr + 1) as i64;
+        regs.set(dst.into(), val, mem)
+    })();
+
+    if let Err(fault) = outcome {
+        let handler = regs.buffer[TRAP_VEC_REGISTER];
+        if handler != 0 {
+            regs.buffer[TRAP_CAUSE_REGISTER] = fault.code();
+            regs.buffer[TRAP_PC_REGISTER] = addr as i64;
+            regs.buffer[PC_REGISTER] = handler;
+        } else {
+            return Err(fault);
+        }
+    }
+
+    Ok(regs.buffer[HALT_REGISTER] == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::io::BufferIo;
+
+    fn test_memory() -> Memory {
+        Memory::new(Box::new(BufferIo::default()))
+    }
+
+    #[test]
+    fn div_trig_traps_on_divide_by_zero() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+        // div.b (10) defaults to 0, so writing div.a (9) fires div_trig with
+        // a zero divisor straight away.
+        let err = regs.set(9, 10, &mut mem).unwrap_err();
+        assert_eq!(err, Trap::DivideByZero);
+    }
+
+    #[test]
+    fn cio_trig_traps_on_out_of_range_byte() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+        let err = regs.set(16, 1000, &mut mem).unwrap_err();
+        assert_eq!(err, Trap::InvalidChar);
+    }
+
+    #[test]
+    fn io_trig_traps_on_out_of_range_byte() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+        let err = regs.set(18, 1000, &mut mem).unwrap_err();
+        assert_eq!(err, Trap::InvalidChar);
+    }
+
+    #[test]
+    fn step_without_a_handler_propagates_the_trap() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+        // mov #1000, cio -- 1000 doesn't fit in a byte, so cio_trig traps.
+        mem.store32(0, ((0x8000u32 | 1000) << 16) | 16);
+        let err = step(&mut regs, &mut mem).unwrap_err();
+        assert_eq!(err, Trap::InvalidChar);
+    }
+
+    #[test]
+    fn call_and_ret_round_trip_pc_through_the_stack() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+        regs.buffer[SP_REGISTER] = 100;
+        regs.buffer[PC_REGISTER] = 5;
+
+        regs.set(CALL_REGISTER, 20, &mut mem).unwrap();
+        assert_eq!(regs.buffer[PC_REGISTER], 20);
+        assert_eq!(regs.buffer[SP_REGISTER], 99);
+
+        regs.set(RET_REGISTER, 0, &mut mem).unwrap();
+        assert_eq!(regs.buffer[PC_REGISTER], 5);
+        assert_eq!(regs.buffer[SP_REGISTER], 100);
+    }
+
+    #[test]
+    fn call_before_sp_is_initialized_traps() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+        // sp defaults to 0, so the very first call underflows it.
+        let err = regs.set(CALL_REGISTER, 20, &mut mem).unwrap_err();
+        assert_eq!(err, Trap::MemoryOutOfBounds);
+    }
+
+    #[test]
+    fn ret_with_a_negative_sp_traps() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+        // A negative sp can't come from a balanced call/ret sequence, but
+        // ret_trig still has to refuse it rather than misinterpret the sign
+        // bit as a huge address.
+        regs.buffer[SP_REGISTER] = -1;
+        let err = regs.set(RET_REGISTER, 0, &mut mem).unwrap_err();
+        assert_eq!(err, Trap::MemoryOutOfBounds);
+    }
+
+    #[test]
+    fn fadd_fsub_fmul_fdiv_compute_their_operation() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+
+        regs.set(FADD_A, 2.5f64.to_bits() as i64, &mut mem).unwrap();
+        regs.set(FADD_B, 1.5f64.to_bits() as i64, &mut mem).unwrap();
+        assert_eq!(f64::from_bits(regs.buffer[FADD_OUT] as u64), 4.0);
+
+        regs.set(FSUB_A, 2.5f64.to_bits() as i64, &mut mem).unwrap();
+        regs.set(FSUB_B, 1.5f64.to_bits() as i64, &mut mem).unwrap();
+        assert_eq!(f64::from_bits(regs.buffer[FSUB_OUT] as u64), 1.0);
+
+        regs.set(FMUL_A, 2.5f64.to_bits() as i64, &mut mem).unwrap();
+        regs.set(FMUL_B, 2.0f64.to_bits() as i64, &mut mem).unwrap();
+        assert_eq!(f64::from_bits(regs.buffer[FMUL_OUT] as u64), 5.0);
+
+        regs.set(FDIV_A, 5.0f64.to_bits() as i64, &mut mem).unwrap();
+        regs.set(FDIV_B, 2.0f64.to_bits() as i64, &mut mem).unwrap();
+        assert_eq!(f64::from_bits(regs.buffer[FDIV_OUT] as u64), 2.5);
+    }
+
+    #[test]
+    fn fdiv_by_zero_produces_infinity_instead_of_trapping() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+        regs.set(FDIV_A, 1.0f64.to_bits() as i64, &mut mem).unwrap();
+        regs.set(FDIV_B, 0.0f64.to_bits() as i64, &mut mem).unwrap();
+        assert_eq!(f64::from_bits(regs.buffer[FDIV_OUT] as u64), f64::INFINITY);
+    }
+
+    #[test]
+    fn fcmp_sets_out_only_when_a_is_less_than_b() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+
+        regs.set(FCMP_A, 1.0f64.to_bits() as i64, &mut mem).unwrap();
+        regs.set(FCMP_B, 2.0f64.to_bits() as i64, &mut mem).unwrap();
+        assert_eq!(regs.buffer[FCMP_OUT], 1);
+
+        regs.set(FCMP_A, 2.0f64.to_bits() as i64, &mut mem).unwrap();
+        regs.set(FCMP_B, 2.0f64.to_bits() as i64, &mut mem).unwrap();
+        assert_eq!(regs.buffer[FCMP_OUT], 0);
+    }
+
+    #[test]
+    fn ftoi_rounds_per_the_selected_mode() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+        let bits = 2.5f64.to_bits() as i64;
+
+        regs.buffer[FP_ROUND_MODE] = 0; // nearest
+        regs.set(FTOI_IN, bits, &mut mem).unwrap();
+        assert_eq!(regs.buffer[FTOI_OUT], 3);
+
+        regs.buffer[FP_ROUND_MODE] = 1; // toward zero
+        regs.set(FTOI_IN, bits, &mut mem).unwrap();
+        assert_eq!(regs.buffer[FTOI_OUT], 2);
+
+        regs.buffer[FP_ROUND_MODE] = 2; // up
+        regs.set(FTOI_IN, bits, &mut mem).unwrap();
+        assert_eq!(regs.buffer[FTOI_OUT], 3);
+
+        regs.buffer[FP_ROUND_MODE] = 3; // down
+        regs.set(FTOI_IN, bits, &mut mem).unwrap();
+        assert_eq!(regs.buffer[FTOI_OUT], 2);
+    }
+
+    #[test]
+    fn ftoi_saturates_instead_of_panicking_on_nan_and_infinity() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+
+        regs.set(FTOI_IN, f64::NAN.to_bits() as i64, &mut mem).unwrap();
+        assert_eq!(regs.buffer[FTOI_OUT], 0);
+
+        regs.set(FTOI_IN, f64::INFINITY.to_bits() as i64, &mut mem).unwrap();
+        assert_eq!(regs.buffer[FTOI_OUT], i64::MAX);
+
+        regs.set(FTOI_IN, f64::NEG_INFINITY.to_bits() as i64, &mut mem).unwrap();
+        assert_eq!(regs.buffer[FTOI_OUT], i64::MIN);
+    }
+
+    #[test]
+    fn itof_converts_integer_to_float_bits() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+        regs.set(ITOF_IN, 42, &mut mem).unwrap();
+        assert_eq!(f64::from_bits(regs.buffer[ITOF_OUT] as u64), 42.0);
+    }
+
+    #[test]
+    fn nan_and_infinity_round_trip_losslessly_through_a_float_register() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+
+        let nan_bits = f64::NAN.to_bits() as i64;
+        regs.set(FADD_A, nan_bits, &mut mem).unwrap();
+        assert_eq!(regs.get(FADD_A, &mut mem).unwrap(), nan_bits);
+
+        let inf_bits = f64::INFINITY.to_bits() as i64;
+        regs.set(FSUB_B, inf_bits, &mut mem).unwrap();
+        assert_eq!(regs.get(FSUB_B, &mut mem).unwrap(), inf_bits);
+    }
+
+    #[test]
+    fn step_with_a_handler_redirects_pc_and_fills_in_the_trap_registers() {
+        let mut regs = Registers::new();
+        let mut mem = test_memory();
+        regs.buffer[TRAP_VEC_REGISTER] = 42;
+        mem.store32(0, ((0x8000u32 | 1000) << 16) | 16);
+
+        let keep_running = step(&mut regs, &mut mem).unwrap();
+
+        assert!(keep_running);
+        assert_eq!(regs.buffer[PC_REGISTER], 42);
+        assert_eq!(regs.buffer[TRAP_CAUSE_REGISTER], Trap::InvalidChar.code());
+        assert_eq!(regs.buffer[TRAP_PC_REGISTER], 0);
+    }
+}
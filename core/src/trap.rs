@@ -0,0 +1,44 @@
+//! Faults raised by register triggers and the execution loop.
+//!
+//! Trigger callbacks used to paper over these conditions (returning the
+//! dividend on divide-by-zero, unwrapping `char::from_u32`, unwrapping
+//! fallible numeric conversions) or let them panic and tear down the whole
+//! VM. They now return `Result<(), Trap>` instead, so `registers::step` can
+//! route a fault through the trap-vector register.
+
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trap {
+    DivideByZero,
+    InvalidChar,
+    MemoryOutOfBounds,
+    InvalidRegister,
+}
+
+impl Trap {
+    /// Stable numeric code exposed to a trap handler via the `trap.cause`
+    /// register, so handler code can branch on it without depending on
+    /// this enum's `Debug` layout.
+    pub fn code(self) -> i64 {
+        match self {
+            Trap::DivideByZero => 1,
+            Trap::InvalidChar => 2,
+            Trap::MemoryOutOfBounds => 3,
+            Trap::InvalidRegister => 4,
+        }
+    }
+}
+
+impl fmt::Display for Trap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Trap::DivideByZero => write!(f, "divide by zero"),
+            Trap::InvalidChar => write!(f, "invalid character code"),
+            Trap::MemoryOutOfBounds => write!(f, "memory access out of bounds"),
+            Trap::InvalidRegister => write!(f, "invalid register index"),
+        }
+    }
+}
+
+impl core::error::Error for Trap {}
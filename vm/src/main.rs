@@ -0,0 +1,98 @@
+#[cfg(feature = "termion-io")]
+mod termion_io;
+
+use mov_vm_core::assembler;
+use mov_vm_core::io::VmIo;
+use mov_vm_core::memory::Memory;
+use mov_vm_core::registers::Registers;
+
+/// Prints a short banner through `cio`, one byte per instruction, then writes
+/// `halt` to stop — built through [`assembler::assemble`] so the demo program
+/// can never drift from what the assembler actually produces.
+const DEMO_SOURCE: &str = "\
+    mov #72, cio  ; H
+    mov #101, cio ; e
+    mov #108, cio ; l
+    mov #108, cio ; l
+    mov #111, cio ; o
+    mov #44, cio  ; ,
+    mov #32, cio  ; (space)
+    mov #109, cio ; m
+    mov #111, cio ; o
+    mov #118, cio ; v
+    mov #45, cio  ; -
+    mov #118, cio ; v
+    mov #109, cio ; m
+    mov #33, cio  ; !
+    mov #10, cio  ; \\n
+    mov #1, halt
+";
+
+#[cfg(feature = "termion-io")]
+fn default_io() -> Box<dyn VmIo> {
+    Box::new(termion_io::TermionIo::new().unwrap())
+}
+
+/// `VmIo` for the plain (non-`termion-io`) build: no input, writes go
+/// straight to stdout. `mov_vm_core::io::BufferIo` would swallow the demo
+/// program's output silently, which isn't what running the binary should do.
+#[cfg(not(feature = "termion-io"))]
+struct StdoutIo;
+
+#[cfg(not(feature = "termion-io"))]
+impl VmIo for StdoutIo {
+    fn read_byte(&mut self) -> Option<u8> {
+        None
+    }
+
+    fn write_byte(&mut self, byte: u8) {
+        use std::io::Write;
+        let _ = std::io::stdout().write_all(&[byte]);
+    }
+}
+
+#[cfg(not(feature = "termion-io"))]
+fn default_io() -> Box<dyn VmIo> {
+    Box::new(StdoutIo)
+}
+
+/// Prints `program`'s disassembly when invoked as `mov-vm --disasm`. Behind
+/// the `disasm` feature so a build without it doesn't need to recognize the
+/// flag at all.
+#[cfg(feature = "disasm")]
+fn maybe_dump_disassembly(mem: &Memory, word_count: u64) {
+    if std::env::args().any(|arg| arg == "--disasm") {
+        print!("{}", mov_vm_core::disasm::disassemble(mem, 0, word_count));
+    }
+}
+
+#[cfg(not(feature = "disasm"))]
+fn maybe_dump_disassembly(_mem: &Memory, _word_count: u64) {}
+
+fn main() {
+    let program = assembler::assemble(DEMO_SOURCE).expect("demo program failed to assemble");
+
+    let mut regs = Registers::new();
+    let mut mem = Memory::new(default_io());
+    mem.store(&program, 0);
+    maybe_dump_disassembly(&mem, program.len() as u64 / 4);
+
+    let mut ticks: u64 = 0;
+    loop {
+        match mov_vm_core::step(&mut regs, &mut mem) {
+            Ok(true) => {}
+            Ok(false) => {
+                println!("halted: tick={ticks}, pc={}", regs.buffer[mov_vm_core::registers::PC_REGISTER]);
+                break;
+            }
+            Err(fault) => {
+                println!(
+                    "trap: {fault} at pc={}, tick={ticks}",
+                    regs.buffer[mov_vm_core::registers::PC_REGISTER]
+                );
+                break;
+            }
+        }
+        ticks += 1;
+    }
+}
@@ -0,0 +1,52 @@
+//! `VmIo` backend that drives a real terminal via `termion`. Feature-gated
+//! behind `termion-io` since it's the one piece of the VM that needs a real
+//! TTY; everything else is happy with [`mov_vm_core::io::BufferIo`].
+
+use std::io::Read;
+use std::io::Write;
+
+use termion::raw::IntoRawMode;
+use termion::raw::RawTerminal;
+
+use mov_vm_core::io::VmIo;
+
+pub struct TermionIo {
+    input: std::io::Bytes<termion::AsyncReader>,
+    output: RawTerminal<std::io::Stdout>,
+}
+
+impl TermionIo {
+    pub fn new() -> std::io::Result<Self> {
+        // `async_stdin()` is already a non-blocking, in-memory reader (it
+        // spawns a background thread that buffers into a pipe); wrapping it
+        // in a `BufReader` would just add a second buffer in front of one.
+        #[allow(clippy::unbuffered_bytes)]
+        let input = termion::async_stdin().bytes();
+        Ok(TermionIo {
+            input,
+            output: std::io::stdout().into_raw_mode()?,
+        })
+    }
+}
+
+impl VmIo for TermionIo {
+    fn read_byte(&mut self) -> Option<u8> {
+        match self.input.next() {
+            Some(Ok(0)) => None,
+            Some(Ok(n)) => Some(n),
+            Some(Err(_)) => None,
+            None => None,
+        }
+    }
+
+    fn write_byte(&mut self, byte: u8) {
+        let _ = self.output.write_all(&[byte]);
+    }
+
+    fn clear_screen(&mut self) {
+        let _ = self.output.lock().flush();
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        print!("\x1B[1;1H\x1B[J");
+        let _ = self.output.flush();
+    }
+}